@@ -5,7 +5,7 @@
 
 use super::{app, icon::create_icns_file};
 use crate::{
-  bundle::{common::CommandExt, Bundle},
+  bundle::{common::CommandExt, settings::Position, settings::Size, Bundle},
   PackageType::MacOsBundle,
   Settings,
 };
@@ -20,6 +20,13 @@ use std::{
   process::{Command, Stdio},
 };
 
+const DEFAULT_WINDOW_SIZE: Size = Size {
+  width: 571,
+  height: 375,
+};
+const DEFAULT_APP_POSITION: Position = Position { x: 75, y: 64 };
+const DEFAULT_APP_FOLDER_POSITION: Position = Position { x: 396, y: 64 };
+
 /// Bundles the project.
 /// Returns a vector of PathBuf that shows where the DMG was created.
 pub fn bundle_project(settings: &Settings, bundles: &[Bundle]) -> crate::Result<Vec<PathBuf>> {
@@ -93,6 +100,13 @@ pub fn bundle_project(settings: &Settings, bundles: &[Bundle]) -> crate::Result<
     .output()
     .expect("Failed to chmod script");
 
+  let dmg_settings = settings.macos().dmg.clone().unwrap_or_default();
+  let window_size = dmg_settings.window_size.unwrap_or(DEFAULT_WINDOW_SIZE);
+  let app_position = dmg_settings.app_position.unwrap_or(DEFAULT_APP_POSITION);
+  let app_folder_position = dmg_settings
+    .app_folder_position
+    .unwrap_or(DEFAULT_APP_FOLDER_POSITION);
+
   let mut args = vec![
     "--no-internet-enable".to_owned(),
     "--volname".to_owned(),
@@ -101,40 +115,55 @@ pub fn bundle_project(settings: &Settings, bundles: &[Bundle]) -> crate::Result<
     "100".to_owned(),
     "--icon".to_owned(),
     bundle_file_name.clone(),
-    "75".to_owned(),
-    "64".to_owned(),
+    app_position.x.to_string(),
+    app_position.y.to_string(),
     "--app-drop-link".to_owned(),
-    "396".to_owned(),
-    "64".to_owned(),
+    app_folder_position.x.to_string(),
+    app_folder_position.y.to_string(),
     "--window-size".to_owned(),
-    "571".to_owned(),
-    "375".to_owned(),
+    window_size.width.to_string(),
+    window_size.height.to_string(),
     "--hide-extension".to_owned(),
     bundle_file_name.clone(),
   ];
 
+  if let Some(window_position) = dmg_settings.window_position {
+    args.push("--window-pos".to_owned());
+    args.push(window_position.x.to_string());
+    args.push(window_position.y.to_string());
+  }
+
   if let Some(attachments) = &settings.macos().attachments {
 
     for (index, pair) in attachments.chunks(2).enumerate() {
-      let first_name = pair[0].file_name().unwrap().to_str().unwrap();
+      let grid_y = 64 + (index + 1) * (100 + 60);
+
+      let first = &pair[0];
+      let first_name = first.path.file_name().unwrap().to_str().unwrap();
+      let first_position = first.position.unwrap_or(Position {
+        x: 75,
+        y: grid_y as u32,
+      });
 
       args.push("--add-file".to_owned());
       args.push(first_name.to_owned());
       args.push(first_name.to_owned());
+      args.push(first_position.x.to_string());
+      args.push(first_position.y.to_string());
 
-      args.push("75".to_owned());
-      let y = 64 + (index + 1) * (100 + 60);
-      let y = y.to_string();
-      args.push(y.clone());
       if pair.len() == 2 {
-        let second_name = pair[1].file_name().unwrap().to_str().unwrap();
+        let second = &pair[1];
+        let second_name = second.path.file_name().unwrap().to_str().unwrap();
+        let second_position = second.position.unwrap_or(Position {
+          x: 396,
+          y: grid_y as u32,
+        });
 
         args.push("--add-file".to_owned());
         args.push(second_name.to_owned());
         args.push(second_name.to_owned());
-
-        args.push("396".to_owned());
-        args.push(y);
+        args.push(second_position.x.to_string());
+        args.push(second_position.y.to_string());
       }
     }
   }
@@ -0,0 +1,166 @@
+// Copyright 2016-2019 Cargo-Bundle developers <https://github.com/burtonageo/cargo-bundle>
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::path::PathBuf;
+
+/// An x/y coordinate used to position an element of the DMG window.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct Position {
+  pub x: u32,
+  pub y: u32,
+}
+
+/// A width/height pair used to size the DMG window.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct Size {
+  pub width: u32,
+  pub height: u32,
+}
+
+/// Configuration for the visual layout of the DMG window, set via
+/// `tauri.conf.json`'s `bundle.macOS.dmg` and exposed at build time through
+/// `settings.macos().dmg`. An unset window size, app position or Applications
+/// folder position falls back to the default layout that `bundle_project`
+/// passes to `bundle_dmg.sh` (a 571x375 window, the app at 75,64 and the
+/// Applications folder at 396,64); an unset window position passes no
+/// `--window-pos`, so the script places the window itself.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DmgSettings {
+  /// Position of the DMG window on screen.
+  pub window_position: Option<Position>,
+  /// Size of the DMG window.
+  pub window_size: Option<Size>,
+  /// Position of the app icon inside the DMG window.
+  pub app_position: Option<Position>,
+  /// Position of the Applications folder drop-link inside the DMG window.
+  pub app_folder_position: Option<Position>,
+}
+
+/// A file to be added to the DMG window, with an optional explicit position.
+/// When `position` is left unset, `bundle_project` falls back to the
+/// auto-computed grid layout used for attachments without coordinates.
+///
+/// Accepts either a plain path string, for backward compatibility with
+/// existing `tauri.conf.json` files (`"path/to/file"`), or an object with an
+/// explicit position (`{ "path": "path/to/file", "x": 75, "y": 64 }`). It is
+/// serialized back out in the same two shapes, so round-tripping (schema
+/// export, config merge) produces input the deserializer accepts.
+#[derive(Debug, Clone)]
+pub struct DmgAttachment {
+  pub path: PathBuf,
+  pub position: Option<Position>,
+}
+
+impl Serialize for DmgAttachment {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match &self.position {
+      None => self.path.serialize(serializer),
+      Some(position) => {
+        #[derive(Serialize)]
+        struct WithPosition<'a> {
+          path: &'a PathBuf,
+          x: u32,
+          y: u32,
+        }
+
+        WithPosition {
+          path: &self.path,
+          x: position.x,
+          y: position.y,
+        }
+        .serialize(serializer)
+      }
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for DmgAttachment {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum DmgAttachmentRepr {
+      Path(PathBuf),
+      WithPosition { path: PathBuf, x: u32, y: u32 },
+    }
+
+    Ok(match DmgAttachmentRepr::deserialize(deserializer)? {
+      DmgAttachmentRepr::Path(path) => DmgAttachment {
+        path,
+        position: None,
+      },
+      DmgAttachmentRepr::WithPosition { path, x, y } => DmgAttachment {
+        path,
+        position: Some(Position { x, y }),
+      },
+    })
+  }
+}
+
+/// macOS-specific bundle settings, set via `tauri.conf.json`'s
+/// `bundle.macOS` and read back through `settings.macos()`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MacOsSettings {
+  /// Identity used to code sign the macOS application bundle and the DMG.
+  pub signing_identity: Option<String>,
+  /// Path to the license file shown in the DMG EULA sheet.
+  pub license: Option<PathBuf>,
+  /// Path to an image used as the DMG window background.
+  pub background: Option<PathBuf>,
+  /// Extra files attached to the DMG window alongside the app icon.
+  pub attachments: Option<Vec<DmgAttachment>>,
+  /// Layout configuration for the generated DMG window.
+  pub dmg: Option<DmgSettings>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn dmg_attachment_deserializes_from_plain_path_string() {
+    let attachment: DmgAttachment = serde_json::from_str("\"README.md\"").unwrap();
+    assert_eq!(attachment.path, PathBuf::from("README.md"));
+    assert!(attachment.position.is_none());
+  }
+
+  #[test]
+  fn dmg_attachment_deserializes_from_object_with_position() {
+    let attachment: DmgAttachment =
+      serde_json::from_str(r#"{ "path": "README.md", "x": 75, "y": 64 }"#).unwrap();
+    assert_eq!(attachment.path, PathBuf::from("README.md"));
+    assert_eq!(attachment.position.unwrap().x, 75);
+    assert_eq!(attachment.position.unwrap().y, 64);
+  }
+
+  #[test]
+  fn dmg_attachment_round_trips_through_serialization() {
+    let with_position = DmgAttachment {
+      path: PathBuf::from("README.md"),
+      position: Some(Position { x: 75, y: 64 }),
+    };
+    let json = serde_json::to_string(&with_position).unwrap();
+    let parsed: DmgAttachment = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.path, with_position.path);
+    assert_eq!(parsed.position.unwrap().x, 75);
+    assert_eq!(parsed.position.unwrap().y, 64);
+
+    let without_position = DmgAttachment {
+      path: PathBuf::from("README.md"),
+      position: None,
+    };
+    let json = serde_json::to_string(&without_position).unwrap();
+    assert_eq!(json, "\"README.md\"");
+  }
+}